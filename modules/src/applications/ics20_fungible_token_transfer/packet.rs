@@ -13,6 +13,7 @@ pub struct PacketData {
     pub token: Coin,
     pub sender: Signer,
     pub receiver: Signer,
+    pub memo: String,
 }
 
 impl TryFrom<RawPacketData> for PacketData {
@@ -25,6 +26,7 @@ impl TryFrom<RawPacketData> for PacketData {
             token: Coin { denom, amount },
             sender: raw_pkt_data.sender.into(),
             receiver: raw_pkt_data.receiver.into(),
+            memo: raw_pkt_data.memo,
         })
     }
 }
@@ -36,6 +38,7 @@ impl From<PacketData> for RawPacketData {
             amount: pkt_data.token.amount.to_string(),
             sender: pkt_data.sender.to_string(),
             receiver: pkt_data.receiver.to_string(),
+            memo: pkt_data.memo,
         }
     }
 }
@@ -2,9 +2,14 @@
    Constructs for implementing overrides for test cases.
 */
 
+use std::path::PathBuf;
+
 use ibc::core::ics04_channel::channel::Order;
 use ibc::core::ics24_host::identifier::PortId;
-use ibc_relayer::config::Config;
+use ibc::core::ics26_routing::context::RouterBuilder;
+use ibc_relayer::config::filter::PacketFilter;
+use ibc_relayer::config::types::Memo;
+use ibc_relayer::config::{Config, Store};
 use ibc_relayer::supervisor::SupervisorHandle;
 
 use crate::error::Error;
@@ -56,8 +61,77 @@ pub trait TestOverrides {
 
        Implemented for [`RelayerConfigOverride`].
     */
-    fn modify_relayer_config(&self, _config: &mut Config) {
-        // No modification by default
+    fn modify_relayer_config(&self, config: &mut Config) {
+        for chain_config in config.chains.iter_mut() {
+            if let Some(memo) = self.tx_memo_overwrite() {
+                chain_config.memo_prefix = memo;
+            }
+
+            chain_config.key_store_type = self.key_store_type();
+            if let Some(key_store_folder) = self.key_store_directory() {
+                chain_config.key_store_folder = key_store_folder;
+            }
+
+            chain_config.packet_filter = self.packet_filter();
+        }
+    }
+
+    /**
+       Overwrite the memo that Hermes would otherwise build for transactions
+       submitted to a chain. Returns `None` by default, in which case the
+       relayer's own memo is left untouched.
+
+       Returning a [`Memo`] directly, rather than a raw `String`, pushes
+       validation onto the test author and keeps this method infallible.
+
+       This is useful for chains that impose a small character limit on the
+       transaction memo, for which the default relayer-appended memo would
+       overflow.
+
+       Implemented for [`RelayerConfigOverride`].
+    */
+    fn tx_memo_overwrite(&self) -> Option<Memo> {
+        None
+    }
+
+    /**
+       Return the keyring store type used for every chain config during
+       test setup. Defaults to the in-memory [`Store::Memory`] store, so that
+       keys do not persist across driver restarts unless a test opts in.
+
+       Implemented for [`KeyringOverride`].
+    */
+    fn key_store_type(&self) -> Store {
+        Store::Memory
+    }
+
+    /**
+       Return the directory the keyring should be stored in for every chain
+       config during test setup. Returns `None` by default, in which case
+       each chain's default keyring directory is left untouched.
+
+       This is useful for tests that need keys to survive a relayer driver
+       restart, by pointing every chain at the same persistent directory.
+
+       Implemented for [`KeyringOverride`].
+    */
+    fn key_store_directory(&self) -> Option<PathBuf> {
+        None
+    }
+
+    /**
+       Return the packet filter used for every chain config during test
+       setup. Defaults to allowing all channels, so that existing tests
+       keep relaying every packet unless they opt into a policy.
+
+       This allows tests to exercise policy-based relaying, asserting that
+       packets on a non-allowed `(PortId, ChannelId)` pair are ignored while
+       allowed channels still clear.
+
+       Implemented for [`PacketFilterOverride`].
+    */
+    fn packet_filter(&self) -> PacketFilter {
+        PacketFilter::allow_all()
     }
 
     /**
@@ -110,6 +184,81 @@ pub trait TestOverrides {
     fn channel_order(&self) -> Order {
         Order::Unordered
     }
+
+    /**
+       Register additional IBC [`Module`](ibc::core::ics26_routing::context::Module)s,
+       keyed by [`ModuleId`](ibc::core::ics26_routing::context::ModuleId), on
+       the given `router_builder`. Returns the given `router_builder`
+       unchanged by default.
+
+       The test setup does not call this method, so implementing it has no
+       effect on the chains or the relayer under test.
+
+       Implemented for [`RouterOverride`].
+    */
+    fn register_modules<B: RouterBuilder>(&self, router_builder: B) -> B {
+        router_builder
+    }
+}
+
+/**
+   Specialized trait for overriding the keyring used during test setup.
+
+   This trait is auto-implemented for any test case that implements
+   [`TestOverrides`], and is applied to every chain config as part of
+   [`TestOverrides::modify_relayer_config`].
+*/
+pub trait KeyringOverride {
+    fn key_store_type(&self) -> Store;
+
+    fn key_store_directory(&self) -> Option<PathBuf>;
+}
+
+impl<Test: TestOverrides> KeyringOverride for Test {
+    fn key_store_type(&self) -> Store {
+        TestOverrides::key_store_type(self)
+    }
+
+    fn key_store_directory(&self) -> Option<PathBuf> {
+        TestOverrides::key_store_directory(self)
+    }
+}
+
+/**
+   Specialized trait for overriding the packet filter used during test
+   setup.
+
+   This trait is auto-implemented for any test case that implements
+   [`TestOverrides`], and is applied to every chain config as part of
+   [`TestOverrides::modify_relayer_config`], alongside [`RelayerConfigOverride`].
+*/
+pub trait PacketFilterOverride {
+    fn packet_filter(&self) -> PacketFilter;
+}
+
+impl<Test: TestOverrides> PacketFilterOverride for Test {
+    fn packet_filter(&self) -> PacketFilter {
+        TestOverrides::packet_filter(self)
+    }
+}
+
+/**
+   Specialized trait for registering custom IBC modules on a router.
+
+   This trait is auto-implemented for any test case that implements
+   [`TestOverrides`]. Unlike [`RelayerConfigOverride`] and
+   [`SupervisorOverride`], it is not called during test setup, so
+   implementing [`TestOverrides::register_modules`] has no effect on a
+   test.
+*/
+pub trait RouterOverride {
+    fn register_modules<B: RouterBuilder>(&self, router_builder: B) -> B;
+}
+
+impl<Test: TestOverrides> RouterOverride for Test {
+    fn register_modules<B: RouterBuilder>(&self, router_builder: B) -> B {
+        TestOverrides::register_modules(self, router_builder)
+    }
 }
 
 impl<Test: TestOverrides> HasOverrides for Test {
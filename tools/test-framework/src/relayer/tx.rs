@@ -10,10 +10,22 @@ use ibc_relayer::chain::cosmos::wait::wait_for_block_commits;
 use ibc_relayer::config::types::Memo;
 use ibc_relayer::config::ChainConfig;
 use ibc_relayer::keyring::KeyEntry;
+use prost::Message;
 use tendermint_rpc::HttpClient;
 
 use crate::error::{handle_generic_error, Error};
 
+/**
+   Configures how [`simple_send_tx`] splits the provided messages into
+   multiple batches, mirroring the `max_msg_num` / `max_tx_size` limits
+   that the relayer enforces in production.
+*/
+#[derive(Clone, Debug)]
+pub struct BatchConfig {
+    pub max_msg_num: usize,
+    pub max_tx_size: usize,
+}
+
 /**
  A simplified version of send_tx that does not depend on `ChainHandle`.
 
@@ -22,7 +34,12 @@ use crate::error::{handle_generic_error, Error};
 
  - Query the account information on the fly. This may introduce more
    overhead in production, but does not matter in testing.
- - Do not split the provided messages into smaller batches.
+ - Do not split the provided messages into smaller batches, unless a
+   [`BatchConfig`] is given.
+ - Use the given `memo` as-is, which allows tests to assert the exact
+   memo bytes that land on chain. Callers that want to respect a per-chain
+   memo overwrite (e.g. one set through a test's `tx_memo_overwrite`
+   override) should pass `&config.memo_prefix` explicitly.
  - Wait for TX sync result, and error if any result contains
    error event.
 */
@@ -31,6 +48,7 @@ pub async fn simple_send_tx(
     key_entry: &KeyEntry,
     memo: &Memo,
     messages: Vec<Any>,
+    batch_config: Option<BatchConfig>,
 ) -> Result<(), Error> {
     let rpc_client = HttpClient::new(config.rpc_addr.clone()).map_err(handle_generic_error)?;
 
@@ -41,27 +59,36 @@ pub async fn simple_send_tx(
         .await?
         .into();
 
-    let message_count = messages.len();
+    let mut account = account;
 
-    let response = estimate_fee_and_send_tx(
-        config,
-        &rpc_client,
-        &grpc_address,
-        key_entry,
-        &account,
-        memo,
-        messages,
-    )
-    .await?;
+    let batches = batch_messages(messages, batch_config.as_ref());
 
-    let events_per_tx = vec![IbcEvent::default(); message_count];
+    let mut tx_sync_results = Vec::with_capacity(batches.len());
 
-    let tx_sync_result = TxSyncResult {
-        response,
-        events: events_per_tx,
-    };
+    for batch in batches {
+        let message_count = batch.len();
+
+        let response = estimate_fee_and_send_tx(
+            config,
+            &rpc_client,
+            &grpc_address,
+            key_entry,
+            &account,
+            memo,
+            batch,
+        )
+        .await?;
+
+        // Each batch is a separate transaction from the same account.
+        account.sequence.increment_mut();
 
-    let mut tx_sync_results = vec![tx_sync_result];
+        let events_per_tx = vec![IbcEvent::default(); message_count];
+
+        tx_sync_results.push(TxSyncResult {
+            response,
+            events: events_per_tx,
+        });
+    }
 
     wait_for_block_commits(
         &config.id,
@@ -82,3 +109,40 @@ pub async fn simple_send_tx(
 
     Ok(())
 }
+
+/**
+   Splits `messages` into batches according to `batch_config`. Returns a
+   single batch containing all the messages when no config is given, which
+   preserves the original unbatched behavior.
+*/
+fn batch_messages(messages: Vec<Any>, batch_config: Option<&BatchConfig>) -> Vec<Vec<Any>> {
+    let batch_config = match batch_config {
+        Some(batch_config) => batch_config,
+        None => return vec![messages],
+    };
+
+    let mut batches = Vec::new();
+    let mut current_batch = Vec::new();
+    let mut current_size = 0;
+
+    for message in messages {
+        let message_size = message.encoded_len();
+
+        if !current_batch.is_empty()
+            && (current_batch.len() >= batch_config.max_msg_num
+                || current_size + message_size > batch_config.max_tx_size)
+        {
+            batches.push(core::mem::take(&mut current_batch));
+            current_size = 0;
+        }
+
+        current_size += message_size;
+        current_batch.push(message);
+    }
+
+    if !current_batch.is_empty() {
+        batches.push(current_batch);
+    }
+
+    batches
+}